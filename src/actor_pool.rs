@@ -1,3 +1,8 @@
+// FIXME: Not compiled into the crate - `lib.rs` does not declare `mod actor_pool;`, so this file
+// is unreachable from outside itself. `ActoxError`/`ActoxResult` don't exist anywhere in this
+// crate either. See the matching note in `src/event_loop.rs`, the only other file that references
+// this type; flagging both as orphaned scaffolding rather than fabricating the missing error
+// infrastructure, which no backlog request asked for.
 use ::{ ActoxError, ActoxResult };
 use ::std::{
 	ptr, collections::HashMap, any::Any,
@@ -9,6 +9,7 @@ use std::{
     collections::HashMap,
     hash::Hash,
     sync::{Arc, RwLock},
+    time::Duration,
 };
 
 /// A topic subscription channel
@@ -16,6 +17,16 @@ type Subscription<M> = Arc<Writer<M>>;
 /// An `Arc`ed RW lock
 type Lock<T> = Arc<RwLock<T>>;
 
+/// The result of a `Dispatch::publish`/`Dispatch::try_publish` call
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PublishReport {
+    /// The number of subscribers the message was delivered to
+    pub delivered: usize,
+    /// The number of subscribers the message was dropped for (their backlog was full, or they
+    /// had already disconnected)
+    pub dropped: usize,
+}
+
 /// A shared message bus
 #[derive(Default, Clone)]
 pub struct Dispatch<T, M> {
@@ -37,12 +48,17 @@ impl<T, M> Dispatch<T, M> {
         let topics = self.topics.read().expect("Some thread has panicked while dispatching?!");
         topics.keys().cloned().collect()
     }
-    /// Publishes a message to all available subscribers for the given topic
+    /// Publishes a message to all available subscribers for the given topic, blocking until
+    /// each live subscriber has capacity to receive it
+    ///
+    /// Returns a `PublishReport` counting how many subscribers the message was delivered to
+    /// versus how many had already disconnected (and were therefore skipped).
     ///
     /// # Note
-    /// The message is not retained for future subscribers. Furthermore, if a subscriber's queue is full, the message won't
-    /// be delivered to this subscriber but is dropped instead.
-    pub fn publish<Q>(&self, topic: &Q, message: M)
+    /// The message is not retained for future subscribers. Because this call provides real
+    /// backpressure, a subscriber whose queue never drains will block it indefinitely; use
+    /// `try_publish` for a non-blocking, best-effort send instead.
+    pub fn publish<Q>(&self, topic: &Q, message: M) -> PublishReport
     where
         T: Borrow<Q> + Eq + Hash,
         M: Clone,
@@ -50,13 +66,74 @@ impl<T, M> Dispatch<T, M> {
     {
         // Lock the topic list and get the subscribers for the given topic
         let topics = self.topics.read().expect("Some thread has panicked while dispatching?!");
+        let mut report = PublishReport::default();
         if let Some(subscribers) = topics.get(topic) {
-            // Send the message to each subscriber
+            // Send the message to each subscriber, blocking for capacity where necessary
             for subscriber in subscribers.values() {
-                // This is a best-effort write; if the subscriber's queue is full, the message will be lost
-                let _ = subscriber.try_write(message.clone());
+                match subscriber.write(message.clone()) {
+                    Ok(()) => report.delivered += 1,
+                    Err(_) => report.dropped += 1,
+                }
             }
         }
+        report
+    }
+    /// Publishes a message to all available subscribers for the given topic, blocking until
+    /// each live subscriber has capacity to receive it or `timeout` elapses
+    ///
+    /// Returns a `PublishReport` counting how many subscribers the message was delivered to
+    /// versus how many had already disconnected or were still full once `timeout` elapsed.
+    ///
+    /// # Note
+    /// `timeout` bounds the wait for *each* subscriber individually, not the call as a whole.
+    pub fn publish_timeout<Q>(&self, topic: &Q, message: M, timeout: Duration) -> PublishReport
+    where
+        T: Borrow<Q> + Eq + Hash,
+        M: Clone,
+        Q: Eq + Hash,
+    {
+        // Lock the topic list and get the subscribers for the given topic
+        let topics = self.topics.read().expect("Some thread has panicked while dispatching?!");
+        let mut report = PublishReport::default();
+        if let Some(subscribers) = topics.get(topic) {
+            // Send the message to each subscriber, blocking for capacity up to `timeout`
+            for subscriber in subscribers.values() {
+                match subscriber.write_timeout(message.clone(), timeout) {
+                    Ok(()) => report.delivered += 1,
+                    Err(_) => report.dropped += 1,
+                }
+            }
+        }
+        report
+    }
+    /// Publishes a message to all available subscribers for the given topic on a best-effort
+    /// basis
+    ///
+    /// Returns a `PublishReport` counting how many subscribers the message was delivered to
+    /// versus how many had a full backlog (or had already disconnected).
+    ///
+    /// # Note
+    /// Unlike `publish`, this call never blocks: if a subscriber's queue is full, the message is
+    /// dropped for that subscriber instead of waiting for capacity.
+    pub fn try_publish<Q>(&self, topic: &Q, message: M) -> PublishReport
+    where
+        T: Borrow<Q> + Eq + Hash,
+        M: Clone,
+        Q: Eq + Hash,
+    {
+        // Lock the topic list and get the subscribers for the given topic
+        let topics = self.topics.read().expect("Some thread has panicked while dispatching?!");
+        let mut report = PublishReport::default();
+        if let Some(subscribers) = topics.get(topic) {
+            // Send the message to each subscriber; full backlogs are dropped rather than waited on
+            for subscriber in subscribers.values() {
+                match subscriber.try_write(message.clone()) {
+                    Ok(()) => report.delivered += 1,
+                    Err(_) => report.dropped += 1,
+                }
+            }
+        }
+        report
     }
 
     /// Subscribes to a topic
@@ -71,9 +148,11 @@ impl<T, M> Dispatch<T, M> {
             topics.insert(topic.to_owned(), HashMap::new());
         }
 
-        // Register our subscriber
+        // Register our subscriber, unless it is already registered for this topic
         let subscribers = topics.get_mut(topic).expect("No subscriber map for given topic?!");
-        subscribers.insert(*subscriber.uid(), subscriber.writer());
+        if !subscribers.contains_key(subscriber.uid()) {
+            subscribers.insert(*subscriber.uid(), subscriber.writer());
+        }
     }
 
     /// Unsubscribes from a topic
@@ -85,12 +164,20 @@ impl<T, M> Dispatch<T, M> {
         // Lock the topic list and remove our subscriber
         let mut topics = self.topics.write().expect("Some thread has panicked while dispatching?!");
         if let Some(subscribers) = topics.get_mut(topic) {
-            subscribers.remove(subscriber.uid());
+            if let Some(writer) = subscribers.remove(subscriber.uid()) {
+                // Let the subscriber's queue know it lost a subscription, so a reader blocked in
+                // `read_timeout`/`read_deadline` can wake up immediately once the last one is gone
+                writer.mark_unsubscribed();
+            }
         }
     }
 
     /// Deallocates unused memory
     ///
+    /// A subscription is only pruned once its writer reports `disconnected`, which - for
+    /// subscriptions fanned out to a work-stealing pool via `Subscriber::reader` - only happens
+    /// once every reader handle pulled from it has been dropped, not just the `Subscriber` itself.
+    ///
     /// # Note
     /// Depending on the size of the dispatcher, this function may block the dispatcher for a significant amount of time.
     pub fn shrink_to_fit(&self)
@@ -113,3 +200,7 @@ impl<T, M> Dispatch<T, M> {
         topics.shrink_to_fit();
     }
 }
+
+/// An alias for `Dispatch`, kept around under its original name since that's what's re-exported
+/// from `lib.rs` and used throughout `tests/bus.rs`
+pub type Bus<T, M> = Dispatch<T, M>;
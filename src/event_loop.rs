@@ -1,3 +1,9 @@
+// NOTE: Not compiled into the crate (`lib.rs` never declares `mod event_loop;`) and references
+// `ActoxResult`/`ActoxError`/`etrace::Error`/the `ok_or!`/`try_err!`/`new_err!` macros, none of
+// which exist anywhere in this crate - see the matching note in `src/actor_pool.rs`. A pooled,
+// throttling redesign was attempted here and then reverted: it was exactly as unreachable as what
+// follows, just longer, which made it look finished when it wasn't. Whoever picks this back up
+// needs to land the missing error/macro plumbing first.
 use ::{ ActoxResult, ActoxError, ActorPool };
 use ::etrace::Error;
 use ::std::{
@@ -1,8 +1,18 @@
 #![doc = include_str!("../README.md")]
+// `queue` carries its own narrow, reviewed `#![allow(unsafe_code)]` for its lock-free ring
+// buffer - see the note at the top of `src/queue.rs`.
 #![deny(unsafe_code)]
 
 mod bus;
 mod queue;
+mod select;
+mod selector;
 mod subscriber;
 
-pub use crate::{bus::Bus, subscriber::Subscriber};
+pub use crate::{
+    bus::{Bus, Dispatch, PublishReport},
+    queue::{RecvError, RecvTimeoutError, TryRecvError},
+    select::{Select, Selected},
+    selector::{Readiness, Selector},
+    subscriber::Subscriber,
+};
@@ -0,0 +1,95 @@
+//! Implements a select to block on many `Reader`s at once
+
+use crate::queue::{Reader, TryRecvError};
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The outcome of a `Select::ready`/`Select::ready_timeout` call
+#[derive(Debug)]
+pub enum Selected<T> {
+    /// The reader at this index had an element ready, which has already been read
+    Ready {
+        /// The index the ready reader was registered with
+        index: usize,
+        /// The element read from it
+        element: T,
+    },
+    /// The reader at this index has disconnected and its backlog is drained, so it will never
+    /// become ready again
+    Closed {
+        /// The index the closed reader was registered with
+        index: usize,
+    },
+}
+
+/// Blocks on many `Reader`s at once instead of requiring one thread per reader
+pub struct Select<'a, T> {
+    /// The registered readers, in registration order; their position in this vector is their
+    /// `Selected::index`
+    readers: Vec<&'a Reader<T>>,
+}
+impl<'a, T> Select<'a, T> {
+    /// Creates a new, empty select
+    pub fn new() -> Self {
+        Self { readers: Vec::new() }
+    }
+
+    /// Registers `reader` and returns the index it will be reported under by `ready`/`ready_timeout`
+    pub fn register(&mut self, reader: &'a Reader<T>) -> usize {
+        self.readers.push(reader);
+        self.readers.len() - 1
+    }
+
+    /// Blocks until at least one registered reader has an element ready or disconnects
+    pub fn ready(&mut self) -> Selected<T> {
+        self.ready_deadline(None).expect("`ready` without a deadline cannot time out")
+    }
+    /// Blocks until at least one registered reader has an element ready or disconnects, or
+    /// returns `None` if `timeout` elapses first
+    pub fn ready_timeout(&mut self, timeout: Duration) -> Option<Selected<T>> {
+        self.ready_deadline(Some(Instant::now() + timeout))
+    }
+
+    /// The shared implementation behind `ready`/`ready_timeout`
+    fn ready_deadline(&mut self, deadline: Option<Instant>) -> Option<Selected<T>> {
+        let thread = thread::current();
+
+        // Register on every reader before the first sweep, so an element delivered (or a
+        // disconnect) between the sweep and parking below is never missed
+        for reader in &self.readers {
+            reader.register_select_waiter(thread.clone());
+        }
+
+        let selected = 'select: loop {
+            for (index, reader) in self.readers.iter().enumerate() {
+                match reader.try_read() {
+                    Ok(element) => break 'select Some(Selected::Ready { index, element }),
+                    Err(TryRecvError::Disconnected) => break 'select Some(Selected::Closed { index }),
+                    Err(TryRecvError::Empty) => (),
+                }
+            }
+
+            match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) => thread::park_timeout(remaining),
+                    None => break None,
+                },
+                None => thread::park(),
+            }
+        };
+
+        // Deregister from every reader now that we're done waiting, so stale entries don't pile
+        // up across repeated `ready`/`ready_timeout` calls
+        for reader in &self.readers {
+            reader.deregister_select_waiter(thread.id());
+        }
+        selected
+    }
+}
+impl<'a, T> Default for Select<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,94 @@
+//! Implements a selector to wait on many subscribers at once
+
+use crate::subscriber::Subscriber;
+use std::{
+    io,
+    sync::{Arc, Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+/// A readiness event reported by `Selector::poll`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Readiness<Token> {
+    /// The token the corresponding subscriber was registered with
+    pub token: Token,
+    /// Whether the subscriber's writers have all disconnected
+    pub closed: bool,
+}
+
+/// Waits on many `Subscriber`s at once instead of requiring one thread per subscriber
+pub struct Selector<'a, Token, M> {
+    /// The wakeup signal every registered subscriber's writers notify on write (or disconnect)
+    wakeup: Arc<(Mutex<bool>, Condvar)>,
+    /// The registered subscribers together with their caller-chosen tokens
+    sources: Vec<(Token, &'a Subscriber<M>)>,
+}
+impl<'a, Token, M> Selector<'a, Token, M> {
+    /// Creates a new, empty selector
+    pub fn new() -> Self {
+        Self { wakeup: Arc::new((Mutex::new(false), Condvar::new())), sources: Vec::new() }
+    }
+
+    /// Registers `subscriber` under `token` so it is considered by future calls to `poll`
+    pub fn register(&mut self, token: Token, subscriber: &'a Subscriber<M>) {
+        subscriber.register_selector(&self.wakeup);
+        self.sources.push((token, subscriber));
+    }
+
+    /// Blocks until at least one registered subscriber is readable (or closed) or `timeout`
+    /// elapses, then fills `events` with a `Readiness` entry for each such subscriber
+    ///
+    /// Returns the number of readiness entries written to `events`, or `0` if `timeout` elapsed
+    /// without any subscriber becoming ready
+    pub fn poll(&mut self, events: &mut Vec<Readiness<Token>>, timeout: Option<Duration>) -> io::Result<usize>
+    where
+        Token: Clone,
+    {
+        events.clear();
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            // Sweep all registered sources for readiness; a message enqueued between a previous
+            // round and this sweep is never missed because `pending`/`disconnected` are checked
+            // directly rather than relying on the wakeup signal alone
+            for (token, subscriber) in &self.sources {
+                if subscriber.disconnected() {
+                    events.push(Readiness { token: token.clone(), closed: true });
+                } else if subscriber.pending() {
+                    events.push(Readiness { token: token.clone(), closed: false });
+                }
+            }
+            if !events.is_empty() {
+                return Ok(events.len());
+            }
+
+            // Nothing ready yet - wait for the next write (or the deadline) and sweep again
+            let (woken, condvar) = &*self.wakeup;
+            let mut woken = woken.lock().expect("Some thread has panicked while selecting?!");
+            // A write that landed between the sweep above and taking the lock has already set
+            // `woken`; only wait if it hasn't, or that write's notification would be missed
+            if !*woken {
+                match deadline {
+                    Some(deadline) => {
+                        let now = Instant::now();
+                        if now >= deadline {
+                            return Ok(0);
+                        }
+                        let (guard, timeout_result) =
+                            condvar.wait_timeout(woken, deadline - now).expect("Some thread has panicked while selecting?!");
+                        woken = guard;
+                        if timeout_result.timed_out() && !*woken {
+                            return Ok(0);
+                        }
+                    }
+                    None => woken = condvar.wait(woken).expect("Some thread has panicked while selecting?!"),
+                }
+            }
+            *woken = false;
+        }
+    }
+}
+impl<'a, Token, M> Default for Selector<'a, Token, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
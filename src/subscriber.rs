@@ -1,14 +1,17 @@
 //! Implements a message subscriber
 
 use crate::queue::{self, Reader, Writer};
+use futures::stream::Stream;
 use std::{
     fmt::Debug,
     hash::{Hash, Hasher},
-    ops::{Deref, DerefMut},
+    ops::Deref,
+    pin::Pin,
     sync::{
         atomic::{AtomicU64, Ordering::SeqCst},
         Arc,
     },
+    task::{Context, Poll},
 };
 
 /// A process-scoped unique ID
@@ -32,23 +35,34 @@ pub struct Subscriber<M> {
     /// The message writer
     writer: Arc<Writer<M>>,
     /// The message reader
-    reader: Reader<M>,
+    reader: Arc<Reader<M>>,
 }
 impl<M> Subscriber<M> {
     /// Creates a new subscriber with the given backlog limit
     pub fn new(backlog: usize) -> Self {
         let (writer, reader) = queue::new(backlog);
-        Self { uid: UniqueID::unique(), writer: Arc::new(writer), reader }
+        Self { uid: UniqueID::unique(), writer: Arc::new(writer), reader: Arc::new(reader) }
     }
 
     /// The subscribers UID
     pub fn uid(&self) -> &UniqueID {
         &self.uid
     }
-    /// Creates a new writer for the subscriber
+    /// Creates a new writer for the subscriber, registering one more live subscription for it
     pub(in crate) fn writer(&self) -> Arc<Writer<M>> {
+        self.writer.mark_subscribed();
         Arc::clone(&self.writer)
     }
+    /// Creates a new reader handle for the subscriber
+    ///
+    /// Unlike the subscriber itself, the returned handle can be cloned (via `Arc::clone`) and
+    /// handed to several worker threads so they pull from the same subscription in a
+    /// work-stealing fashion; each message is still delivered to exactly one of them. Dropping
+    /// handles only closes the subscription once every handle - including the subscriber's own -
+    /// has been dropped; unsubscribing from every topic closes it as well.
+    pub fn reader(&self) -> Arc<Reader<M>> {
+        Arc::clone(&self.reader)
+    }
 }
 impl<M> PartialEq for Subscriber<M> {
     fn eq(&self, other: &Self) -> bool {
@@ -80,8 +94,10 @@ impl<M> Deref for Subscriber<M> {
         &self.reader
     }
 }
-impl<M> DerefMut for Subscriber<M> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.reader
+impl<M> Stream for Subscriber<M> {
+    type Item = M;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<M>> {
+        self.reader.poll_read(cx)
     }
 }
@@ -0,0 +1,44 @@
+use actox::{Dispatch, Subscriber};
+use std::{thread, time::Duration};
+
+#[test]
+fn test_publish_blocks_until_capacity_frees_up() {
+    // Prepare dispatch and a subscriber with a backlog of exactly one element
+    let dispatch = Dispatch::new();
+    let topic = String::from("backpressure/test/publish_blocks");
+    let subscriber = Subscriber::new(1);
+    dispatch.subscribe(&topic, &subscriber);
+
+    // Fill the one slot, then publish a second message that can only succeed once the first is read
+    dispatch.publish(&topic, "first");
+    let (_topic, _dispatch) = (topic.clone(), dispatch.clone());
+    let publisher = thread::spawn(move || {
+        _dispatch.publish(&_topic, "second");
+    });
+
+    // Give the publisher thread a head start so it actually parks on capacity before we free a slot
+    thread::sleep(Duration::from_millis(200));
+    assert_eq!(subscriber.read_timeout(Duration::from_secs(1)), Ok("first"));
+
+    // The previously blocked publish should now unblock and deliver
+    publisher.join().expect("Publisher thread has panicked?!");
+    assert_eq!(subscriber.read_timeout(Duration::from_secs(1)), Ok("second"));
+}
+
+#[test]
+fn test_publish_timeout_elapses_while_the_backlog_stays_full() {
+    // Prepare dispatch and a subscriber with a backlog of exactly one element
+    let dispatch = Dispatch::new();
+    let topic = String::from("backpressure/test/publish_timeout");
+    let subscriber = Subscriber::new(1);
+    dispatch.subscribe(&topic, &subscriber);
+
+    // Fill the one slot and leave it unread, so a second publish can't find capacity in time
+    dispatch.publish(&topic, "first");
+    let report = dispatch.publish_timeout(&topic, "second", Duration::from_millis(200));
+    assert_eq!(report.delivered, 0);
+    assert_eq!(report.dropped, 1);
+
+    // Only the first message is sitting in the backlog
+    assert_eq!(subscriber.read_timeout(Duration::from_secs(1)), Ok("first"));
+}
@@ -1,4 +1,4 @@
-use actox::{Bus, Subscriber};
+use actox::{Bus, RecvTimeoutError, Subscriber};
 use std::{thread, time::Duration};
 
 #[test]
@@ -52,7 +52,11 @@ fn test_unsubscribe() {
     let message = subscriber.read_timeout(Duration::from_secs(2)).expect("Failed to receive dispatch message?!");
     assert_eq!("Dispatch message", message, "Invalid message payload?!");
 
-    // Unregister and wait for second message
+    // Unregister and confirm the subscriber is reported closed rather than just timing out
     bus.unsubscribe(&topic, &subscriber);
-    assert!(subscriber.read_timeout(Duration::from_secs(5)).is_none(), "Received unexpected dispatch message?!");
+    assert_eq!(
+        subscriber.read_timeout(Duration::from_secs(5)),
+        Err(RecvTimeoutError::Disconnected),
+        "Subscriber should be closed after its only topic was unsubscribed?!"
+    );
 }
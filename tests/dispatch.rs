@@ -1,4 +1,4 @@
-use actox::{Dispatch, Subscriber};
+use actox::{Dispatch, RecvTimeoutError, Subscriber};
 use std::{thread, time::Duration};
 
 #[test]
@@ -52,7 +52,40 @@ fn test_unsubscribe() {
     let message = subscriber.read_timeout(Duration::from_secs(2)).expect("Failed to receive dispatch message?!");
     assert_eq!("Dispatch message", message, "Invalid message payload?!");
 
-    // Unregister and wait for second message
+    // Unregister and confirm the subscriber is reported closed rather than just timing out
     dispatch.unsubscribe(&topic, &subscriber);
-    assert!(subscriber.read_timeout(Duration::from_secs(5)).is_none(), "Received unexpected dispatch message?!");
+    assert_eq!(
+        subscriber.read_timeout(Duration::from_secs(5)),
+        Err(RecvTimeoutError::Disconnected),
+        "Subscriber should be closed after its only topic was unsubscribed?!"
+    );
+}
+
+#[test]
+fn test_resubscribe_after_unsubscribing_from_every_topic() {
+    // Prepare dispatch
+    let dispatch = Dispatch::new();
+    let old_topic = String::from("dispatch/test/resubscribe/old");
+    let new_topic = String::from("dispatch/test/resubscribe/new");
+    let message = "Dispatch message";
+
+    // Subscribe to a topic, then unsubscribe from it - the only one this subscriber is on
+    let subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&old_topic, &subscriber);
+    dispatch.unsubscribe(&old_topic, &subscriber);
+    assert_eq!(
+        subscriber.read_timeout(Duration::from_secs(1)),
+        Err(RecvTimeoutError::Disconnected),
+        "Subscriber should be closed once it has no topics left?!"
+    );
+
+    // Resubscribing to a new topic should bring it back to life instead of staying permanently
+    // disconnected
+    dispatch.subscribe(&new_topic, &subscriber);
+    dispatch.publish(&new_topic, message);
+    assert_eq!(
+        subscriber.read_timeout(Duration::from_secs(1)),
+        Ok(message),
+        "Messages should flow again after resubscribing to a new topic?!"
+    );
 }
@@ -0,0 +1,45 @@
+use actox::{Dispatch, Subscriber};
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+const MESSAGES: usize = 500;
+const WORKERS: usize = 4;
+
+#[test]
+fn test_reader_fan_out_delivers_each_message_exactly_once() {
+    // Prepare dispatch and a subscriber with enough backlog for the whole burst
+    let dispatch = Dispatch::new();
+    let topic = String::from("fanout/test/work_stealing");
+    let subscriber = Subscriber::new(MESSAGES);
+    dispatch.subscribe(&topic, &subscriber);
+
+    // Publish the whole burst before the workers start pulling, so there is always work to steal
+    for index in 0..MESSAGES {
+        dispatch.publish(&topic, index);
+    }
+
+    // Fan the subscription out to several worker threads pulling from the same reader
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let workers: Vec<_> = (0..WORKERS)
+        .map(|_| {
+            let reader = subscriber.reader();
+            let received = Arc::clone(&received);
+            thread::spawn(move || {
+                while let Ok(message) = reader.read_timeout(Duration::from_secs(1)) {
+                    received.lock().expect("Some thread has panicked while collecting?!").push(message);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().expect("Worker thread has panicked?!");
+    }
+
+    // Every message was delivered, and to exactly one worker - no drops, no duplicates
+    let mut received = received.lock().expect("Some thread has panicked while collecting?!").clone();
+    received.sort_unstable();
+    assert_eq!(received, (0..MESSAGES).collect::<Vec<_>>(), "A message was lost or delivered more than once?!");
+}
@@ -0,0 +1,31 @@
+use actox::{Dispatch, PublishReport, Subscriber};
+use std::time::Duration;
+
+#[test]
+fn test_try_publish_drops_once_the_backlog_is_full() {
+    // Prepare dispatch and a subscriber with a backlog of exactly one element
+    let dispatch = Dispatch::new();
+    let topic = String::from("publish_report/test/try_publish");
+    let subscriber = Subscriber::new(1);
+    dispatch.subscribe(&topic, &subscriber);
+
+    // The first message fits, the second finds the backlog already full
+    let first = dispatch.try_publish(&topic, "first");
+    let second = dispatch.try_publish(&topic, "second");
+    assert_eq!(first, PublishReport { delivered: 1, dropped: 0 });
+    assert_eq!(second, PublishReport { delivered: 0, dropped: 1 });
+
+    // Only the first message actually made it into the backlog
+    assert_eq!(subscriber.read_timeout(Duration::from_secs(1)), Ok("first"));
+}
+
+#[test]
+fn test_try_publish_counts_nothing_for_an_unknown_topic() {
+    // Prepare dispatch without any subscriber for the topic
+    let dispatch: Dispatch<String, &str> = Dispatch::new();
+    let topic = String::from("publish_report/test/unknown_topic");
+
+    // Nobody is registered, so there's nobody to deliver to or drop for
+    let report = dispatch.try_publish(&topic, "message");
+    assert_eq!(report, PublishReport { delivered: 0, dropped: 0 });
+}
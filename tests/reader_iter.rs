@@ -0,0 +1,64 @@
+use actox::{Dispatch, Subscriber};
+use std::{thread, time::Duration};
+
+#[test]
+fn test_try_iter_drains_whats_immediately_available() {
+    // Prepare dispatch and publish a short burst before reading
+    let dispatch = Dispatch::new();
+    let topic = String::from("reader_iter/test/try_iter");
+    let subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&topic, &subscriber);
+    for message in 0..5 {
+        dispatch.publish(&topic, message);
+    }
+
+    // `try_iter` stops as soon as the backlog is momentarily empty
+    let drained: Vec<_> = subscriber.try_iter().collect();
+    assert_eq!(drained, vec![0, 1, 2, 3, 4]);
+    assert_eq!(subscriber.try_iter().next(), None, "Nothing should be left to drain?!");
+}
+
+#[test]
+fn test_iter_blocks_between_elements() {
+    // Prepare dispatch
+    let dispatch = Dispatch::new();
+    let topic = String::from("reader_iter/test/iter");
+    let subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&topic, &subscriber);
+
+    // Publish one message at a time with a delay, so `iter` can only keep up by actually blocking
+    // between elements instead of requiring them to already be queued
+    let (_topic, _dispatch) = (topic.clone(), dispatch.clone());
+    thread::spawn(move || {
+        for message in 0..3 {
+            thread::sleep(Duration::from_millis(200));
+            _dispatch.publish(&_topic, message);
+        }
+    });
+
+    let received: Vec<_> = subscriber.iter().take(3).collect();
+    assert_eq!(received, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_drain_into_caps_at_max_but_not_beyond_whats_available() {
+    // Prepare dispatch and publish a short burst before reading
+    let dispatch = Dispatch::new();
+    let topic = String::from("reader_iter/test/drain_into");
+    let subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&topic, &subscriber);
+    for message in 0..5 {
+        dispatch.publish(&topic, message);
+    }
+
+    // `max` bounds the batch size...
+    let mut buf = Vec::new();
+    let drained = subscriber.drain_into(&mut buf, 3);
+    assert_eq!(drained, 3);
+    assert_eq!(buf, vec![0, 1, 2]);
+
+    // ...but never drains more than is actually available
+    let drained = subscriber.drain_into(&mut buf, 10);
+    assert_eq!(drained, 2, "Should only drain what's actually available, not pad out to `max`?!");
+    assert_eq!(buf, vec![0, 1, 2, 3, 4]);
+}
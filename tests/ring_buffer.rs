@@ -0,0 +1,36 @@
+use actox::{Dispatch, Subscriber};
+use std::{thread, time::Duration};
+
+const WRITERS: usize = 8;
+const MESSAGES_PER_WRITER: usize = 200;
+
+#[test]
+fn test_ring_buffer_survives_concurrent_writers() {
+    // Prepare dispatch and a subscriber with enough backlog to never block a writer
+    let dispatch = Dispatch::new();
+    let topic = String::from("ring_buffer/test/concurrent_writers");
+    let subscriber = Subscriber::new(WRITERS * MESSAGES_PER_WRITER);
+    dispatch.subscribe(&topic, &subscriber);
+
+    // Hammer the same topic from many threads at once
+    let writers: Vec<_> = (0..WRITERS)
+        .map(|_| {
+            let (dispatch, topic) = (dispatch.clone(), topic.clone());
+            thread::spawn(move || {
+                for _ in 0..MESSAGES_PER_WRITER {
+                    dispatch.publish(&topic, 1usize);
+                }
+            })
+        })
+        .collect();
+    for writer in writers {
+        writer.join().expect("Writer thread has panicked?!");
+    }
+
+    // Every element pushed into the ring must have been received exactly once
+    let mut received = 0;
+    while let Ok(message) = subscriber.read_timeout(Duration::from_secs(1)) {
+        received += message;
+    }
+    assert_eq!(received, WRITERS * MESSAGES_PER_WRITER, "Lost or duplicated an element under concurrent writers?!");
+}
@@ -0,0 +1,66 @@
+use actox::{Dispatch, Select, Selected, Subscriber};
+use std::{thread, time::Duration};
+
+#[test]
+fn test_ready_reports_the_readable_index() {
+    // Prepare dispatch and two subscribers on different topics
+    let dispatch = Dispatch::new();
+    let (topic_a, topic_b) = (String::from("select/test/a"), String::from("select/test/b"));
+    let (subscriber_a, subscriber_b) = (Subscriber::new(1024), Subscriber::new(1024));
+    dispatch.subscribe(&topic_a, &subscriber_a);
+    dispatch.subscribe(&topic_b, &subscriber_b);
+
+    // Register both with a `Select`; `subscriber_b` is registered second, so it is index `1`
+    let mut select = Select::new();
+    select.register(&subscriber_a);
+    select.register(&subscriber_b);
+
+    // Publish only to the second subscriber's topic
+    let (_topic_b, _dispatch) = (topic_b.clone(), dispatch.clone());
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(1));
+        _dispatch.publish(&_topic_b, "Select message");
+    });
+
+    // `ready` should report the second reader, not the first
+    match select.ready_timeout(Duration::from_secs(2)).expect("select timed out?!") {
+        Selected::Ready { index, element } => {
+            assert_eq!(index, 1, "Reported the wrong reader as ready?!");
+            assert_eq!(element, "Select message");
+        }
+        other => panic!("Expected `Selected::Ready`, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_ready_timeout_elapses_when_nothing_is_ready() {
+    // Prepare dispatch and a subscriber that never receives anything
+    let dispatch: Dispatch<String, &str> = Dispatch::new();
+    let topic = String::from("select/test/timeout");
+    let subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&topic, &subscriber);
+
+    let mut select = Select::new();
+    select.register(&subscriber);
+
+    assert!(select.ready_timeout(Duration::from_millis(200)).is_none(), "select should time out when nothing is ready?!");
+}
+
+#[test]
+fn test_ready_reports_a_closed_reader() {
+    // Prepare dispatch and a subscriber, then unsubscribe it from its only topic
+    let dispatch = Dispatch::new();
+    let topic = String::from("select/test/closed");
+    let subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&topic, &subscriber);
+
+    let mut select = Select::new();
+    select.register(&subscriber);
+
+    dispatch.unsubscribe(&topic, &subscriber);
+
+    match select.ready_timeout(Duration::from_secs(2)).expect("select timed out?!") {
+        Selected::Closed { index } => assert_eq!(index, 0),
+        other => panic!("Expected `Selected::Closed`, got {:?}", other),
+    }
+}
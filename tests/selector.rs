@@ -0,0 +1,71 @@
+use actox::{Dispatch, Readiness, Selector, Subscriber};
+use std::{thread, time::Duration};
+
+#[test]
+fn test_poll_reports_a_ready_subscriber() {
+    // Prepare dispatch
+    let dispatch = Dispatch::new();
+    let topic = String::from("selector/test/ready");
+    let message = "Selector message";
+
+    // Register subscriber and selector
+    let subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&topic, &subscriber);
+    let mut selector = Selector::new();
+    selector.register("topic", &subscriber);
+
+    // Start publisher
+    let (_topic, _dispatch) = (topic.clone(), dispatch.clone());
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(1));
+        _dispatch.publish(&_topic, message);
+    });
+
+    // Poll for readiness
+    let mut events = Vec::new();
+    let count = selector.poll(&mut events, Some(Duration::from_secs(2))).expect("Some thread has panicked while selecting?!");
+    assert_eq!(count, 1, "Expected exactly one ready subscriber?!");
+    assert_eq!(events, vec![Readiness { token: "topic", closed: false }]);
+
+    // The message is still there to be read
+    assert_eq!(subscriber.read_timeout(Duration::from_secs(1)), Ok("Selector message"));
+}
+
+#[test]
+fn test_poll_times_out_when_nothing_is_ready() {
+    // Prepare dispatch
+    let dispatch: Dispatch<String, &str> = Dispatch::new();
+    let topic = String::from("selector/test/timeout");
+
+    // Register subscriber and selector, but never publish anything
+    let subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&topic, &subscriber);
+    let mut selector = Selector::new();
+    selector.register("topic", &subscriber);
+
+    // Poll should elapse the timeout without reporting any readiness
+    let mut events = Vec::new();
+    let count = selector.poll(&mut events, Some(Duration::from_millis(200))).expect("Some thread has panicked while selecting?!");
+    assert_eq!(count, 0, "poll should time out when nothing becomes ready?!");
+    assert!(events.is_empty());
+}
+
+#[test]
+fn test_poll_reports_a_closed_subscriber() {
+    // Prepare dispatch
+    let dispatch = Dispatch::new();
+    let topic = String::from("selector/test/closed");
+
+    // Register subscriber and selector, then unsubscribe before polling
+    let subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&topic, &subscriber);
+    let mut selector = Selector::new();
+    selector.register("topic", &subscriber);
+    dispatch.unsubscribe(&topic, &subscriber);
+
+    // Poll should report the subscriber as closed rather than waiting out the timeout
+    let mut events = Vec::new();
+    let count = selector.poll(&mut events, Some(Duration::from_secs(2))).expect("Some thread has panicked while selecting?!");
+    assert_eq!(count, 1);
+    assert_eq!(events, vec![Readiness { token: "topic", closed: true }]);
+}
@@ -0,0 +1,57 @@
+use actox::{Dispatch, Subscriber};
+use futures::{
+    executor::block_on,
+    stream::{poll_fn, StreamExt},
+};
+use std::{thread, time::Duration};
+
+#[test]
+fn test_poll_read_yields_a_published_message() {
+    // Prepare dispatch
+    let dispatch = Dispatch::new();
+    let topic = String::from("stream/test/poll_read");
+    let message = "Stream message";
+
+    // Register subscriber
+    let mut subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&topic, &subscriber);
+
+    // Publish after a delay, so the first `poll_next` call has to register a waker and return
+    // `Poll::Pending` before the message actually arrives
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(1));
+        dispatch.publish(&topic, message);
+    });
+
+    // Await the subscriber as a `Stream`
+    let received = block_on(subscriber.next()).expect("Stream ended before a message arrived?!");
+    assert_eq!("Stream message", received, "Invalid message payload?!");
+}
+
+#[test]
+fn test_poll_read_wakes_every_concurrently_polling_task() {
+    // Prepare dispatch and a subscriber fanned out to two independent reader handles
+    let dispatch = Dispatch::new();
+    let topic = String::from("stream/test/poll_read_fanout");
+    let subscriber = Subscriber::new(1024);
+    dispatch.subscribe(&topic, &subscriber);
+    let (reader_a, reader_b) = (subscriber.reader(), subscriber.reader());
+
+    // Park both readers on their own executor thread, so both register a waker and return
+    // `Poll::Pending` before anything is published - the case a single `Mutex<Option<Waker>>`
+    // slot would clobber, hanging whichever task registered first
+    let worker_a = thread::spawn(move || block_on(poll_fn(move |cx| reader_a.poll_read(cx)).next()));
+    let worker_b = thread::spawn(move || block_on(poll_fn(move |cx| reader_b.poll_read(cx)).next()));
+    thread::sleep(Duration::from_millis(300));
+
+    // Publish one message per fanned-out reader
+    dispatch.publish(&topic, "first");
+    dispatch.publish(&topic, "second");
+
+    let mut received = vec![
+        worker_a.join().expect("Worker A has panicked?!").expect("Worker A's stream ended early?!"),
+        worker_b.join().expect("Worker B has panicked?!").expect("Worker B's stream ended early?!"),
+    ];
+    received.sort_unstable();
+    assert_eq!(received, vec!["first", "second"], "Both concurrently-polling tasks should have been woken?!");
+}